@@ -0,0 +1,288 @@
+use rspack_identifier::{Identifier, IdentifierSet};
+use rustc_hash::FxHashMap;
+
+use crate::boundary::ContentDefinedBoundaryOptions;
+use crate::common::{CompareEntriesTiebreaker, SizeMeasurement, SplitChunkSizes, SplitChunksRuntimeOptions};
+use crate::module_group::ModuleGroup;
+use crate::pipeline::{captured_modules, select_and_split_groups};
+use crate::report::SplitChunksAnalysisReport;
+
+/// One candidate configuration to benchmark: a `compare_entries` tiebreaker
+/// and size measurement, plus an optional content-defined boundary pass for
+/// maxSize splitting. Named after the `algotest` mode shipped by the
+/// external chunker crates that benchmark FastCDC/Rabin/AE boundary
+/// algorithms against a real corpus — this is the same idea applied to
+/// `splitChunks` grouping.
+#[derive(Debug, Clone)]
+pub(crate) struct AlgoTestStrategy {
+  pub name: String,
+  pub tiebreaker: CompareEntriesTiebreaker,
+  pub size_measurement: SizeMeasurement,
+  pub boundary: Option<ContentDefinedBoundaryOptions>,
+}
+
+/// Measured outcome of running one [`AlgoTestStrategy`] over a module graph.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct AlgoTestReport {
+  pub strategy_name: String,
+  pub chunk_count: usize,
+  pub mean_chunk_size: f64,
+  pub stddev_chunk_size: f64,
+  pub total_duplicated_bytes: f64,
+  /// Number of chunk boundary hashes that differ from `previous_run`, i.e.
+  /// how many chunks this strategy would invalidate in browser caches
+  /// relative to the prior build. `0` when there's nothing to compare to.
+  pub boundary_changes_vs_previous: usize,
+  /// The same realized/rejected/duplicated-modules breakdown the real
+  /// `splitChunks` JSON analysis output would show for this strategy's run.
+  pub analysis: SplitChunksAnalysisReport,
+}
+
+fn chunk_identity_hash(chunk: &[Identifier]) -> u64 {
+  use std::hash::{Hash, Hasher};
+  let mut hasher = rustc_hash::FxHasher::default();
+  chunk.hash(&mut hasher);
+  hasher.finish()
+}
+
+fn mean_and_stddev(sizes: &[f64]) -> (f64, f64) {
+  if sizes.is_empty() {
+    return (0.0, 0.0);
+  }
+  let mean = sizes.iter().sum::<f64>() / sizes.len() as f64;
+  let variance = sizes.iter().map(|size| (size - mean).powi(2)).sum::<f64>() / sizes.len() as f64;
+  (mean, variance.sqrt())
+}
+
+/// Run `strategies` against the same `candidate_group_sets` — one entry per
+/// set of cache-group candidates competing over overlapping modules, exactly
+/// what `select_and_split_groups` resolves in the real grouping step — and
+/// report, per strategy, the resulting chunk count, mean/stddev chunk size,
+/// total duplicated bytes still left across rejected candidates, and
+/// boundary stability against `previous_chunk_hashes` (pass an empty map on
+/// the first run of a corpus).
+///
+/// Each strategy's `tiebreaker` and `size_measurement` feed straight into the
+/// `pick_best_group` call inside `select_and_split_groups`, so two
+/// strategies that only differ in one of those fields can pick different
+/// winners out of the same candidate sets. `size_measurement` only affects
+/// that pick under `CompareEntriesTiebreaker::PriorityThenSavings`.
+pub(crate) fn run_algotest(
+  strategies: &[AlgoTestStrategy],
+  candidate_group_sets: &[Vec<ModuleGroup>],
+  module_sizes: &FxHashMap<Identifier, SplitChunkSizes>,
+  previous_chunk_hashes: &FxHashMap<String, Vec<u64>>,
+) -> Vec<AlgoTestReport> {
+  strategies
+    .iter()
+    .map(|strategy| {
+      let options = SplitChunksRuntimeOptions {
+        tiebreaker: strategy.tiebreaker,
+        size_measurement: strategy.size_measurement,
+      };
+
+      let result = select_and_split_groups(
+        candidate_group_sets.to_vec(),
+        module_sizes,
+        &SplitChunkSizes::default(),
+        &SplitChunkSizes::default(),
+        strategy.boundary.as_ref(),
+        &options,
+      );
+
+      let chunks = result.realized_groups.iter().map(ModuleGroup::ordered_modules).collect::<Vec<_>>();
+
+      let sizes = chunks
+        .iter()
+        .map(|chunk| {
+          chunk
+            .iter()
+            .filter_map(|id| module_sizes.get(id))
+            .map(crate::common::total_size)
+            .sum::<f64>()
+        })
+        .collect::<Vec<_>>();
+      let (mean_chunk_size, stddev_chunk_size) = mean_and_stddev(&sizes);
+
+      let captured = captured_modules(&result);
+      let total_duplicated_bytes = result
+        .rejected_groups
+        .iter()
+        .flat_map(|group| group.modules.iter())
+        .filter(|id| !captured.contains(id))
+        .filter_map(|id| module_sizes.get(id))
+        .map(crate::common::total_size)
+        .sum();
+
+      let hashes = chunks.iter().map(|chunk| chunk_identity_hash(chunk)).collect::<Vec<_>>();
+      let boundary_changes_vs_previous = previous_chunk_hashes
+        .get(&strategy.name)
+        .map(|previous| {
+          hashes
+            .iter()
+            .zip(previous.iter())
+            .filter(|(current, previous)| current != previous)
+            .count()
+            + hashes.len().abs_diff(previous.len())
+        })
+        .unwrap_or(0);
+
+      let chunk_modules = chunks
+        .iter()
+        .map(|chunk| chunk.iter().copied().collect::<IdentifierSet>())
+        .collect::<Vec<_>>();
+      let analysis =
+        SplitChunksAnalysisReport::build(&result.realized_groups, &result.rejected_groups, chunk_modules.iter());
+
+      AlgoTestReport {
+        strategy_name: strategy.name.clone(),
+        chunk_count: chunks.len(),
+        mean_chunk_size,
+        stddev_chunk_size,
+        total_duplicated_bytes,
+        boundary_changes_vs_previous,
+        analysis,
+      }
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use rspack_core::{ChunkUkey, SourceType};
+
+  use super::*;
+
+  fn corpus_groups(names: &[&str]) -> Vec<Vec<ModuleGroup>> {
+    names
+      .iter()
+      .map(|name| {
+        let mut sizes = SplitChunkSizes::default();
+        sizes.insert(SourceType::JavaScript, 100.0);
+        vec![ModuleGroup {
+          modules: std::iter::once(Identifier::from(*name)).collect(),
+          cache_group_index: 0,
+          cache_group_priority: 0.0,
+          name: name.to_string(),
+          sizes,
+          estimated_compressed_sizes: SplitChunkSizes::default(),
+          chunks: std::iter::once(ChunkUkey::new()).collect(),
+        }]
+      })
+      .collect()
+  }
+
+  fn module_sizes(names: &[&str]) -> FxHashMap<Identifier, SplitChunkSizes> {
+    names
+      .iter()
+      .map(|name| {
+        let mut sizes = SplitChunkSizes::default();
+        sizes.insert(SourceType::JavaScript, 100.0);
+        (Identifier::from(*name), sizes)
+      })
+      .collect()
+  }
+
+  #[test]
+  fn compares_a_no_boundary_strategy_against_a_content_defined_one() {
+    let names = (0..60).map(|i| format!("module-{i}")).collect::<Vec<_>>();
+    let name_refs = names.iter().map(String::as_str).collect::<Vec<_>>();
+    let candidate_group_sets = corpus_groups(&name_refs);
+    let module_sizes = module_sizes(&name_refs);
+
+    let strategies = [
+      AlgoTestStrategy {
+        name: "no-boundary".to_string(),
+        tiebreaker: CompareEntriesTiebreaker::PriorityThenCount,
+        size_measurement: SizeMeasurement::Raw,
+        boundary: None,
+      },
+      AlgoTestStrategy {
+        name: "content-defined".to_string(),
+        tiebreaker: CompareEntriesTiebreaker::PriorityThenSavings,
+        size_measurement: SizeMeasurement::Raw,
+        boundary: Some(ContentDefinedBoundaryOptions {
+          min_size: 0.0,
+          normal_size: 500.0,
+          max_size: 2_000.0,
+        }),
+      },
+    ];
+
+    let reports = run_algotest(&strategies, &candidate_group_sets, &module_sizes, &FxHashMap::default());
+
+    assert_eq!(reports.len(), 2);
+    let no_boundary = &reports[0];
+    let content_defined = &reports[1];
+
+    // With no boundary step every winning group stays its own chunk; the
+    // content-defined strategy additionally splits by max size, so it should
+    // produce at least as many chunks.
+    assert!(content_defined.chunk_count >= no_boundary.chunk_count);
+
+    // A first run against an empty `previous_chunk_hashes` has nothing to
+    // compare against yet.
+    assert_eq!(content_defined.boundary_changes_vs_previous, 0);
+  }
+
+  #[test]
+  fn differing_tiebreakers_pick_different_winners_from_the_same_candidates() {
+    // Two candidates claiming the same modules with equal priority, chunk
+    // count and cache group index: "high-savings" is far larger, so it has
+    // the bigger size reduction. `PriorityThenCount` never looks at size,
+    // while `PriorityThenSavings` should prefer the bigger size reduction.
+    let mut small = SplitChunkSizes::default();
+    small.insert(SourceType::JavaScript, 100.0);
+    let mut large = SplitChunkSizes::default();
+    large.insert(SourceType::JavaScript, 10_000.0);
+
+    let modules: IdentifierSet = std::iter::once(Identifier::from("shared")).collect();
+
+    let low_savings = ModuleGroup {
+      modules: modules.clone(),
+      cache_group_index: 0,
+      cache_group_priority: 0.0,
+      name: "low-savings".to_string(),
+      sizes: small,
+      estimated_compressed_sizes: SplitChunkSizes::default(),
+      chunks: (0..2).map(|_| ChunkUkey::new()).collect(),
+    };
+    let high_savings = ModuleGroup {
+      modules,
+      cache_group_index: 0,
+      cache_group_priority: 0.0,
+      name: "high-savings".to_string(),
+      sizes: large,
+      estimated_compressed_sizes: SplitChunkSizes::default(),
+      chunks: (0..2).map(|_| ChunkUkey::new()).collect(),
+    };
+
+    let candidate_group_sets = vec![vec![low_savings, high_savings]];
+    let module_sizes = FxHashMap::default();
+
+    let strategies = [
+      AlgoTestStrategy {
+        name: "count".to_string(),
+        tiebreaker: CompareEntriesTiebreaker::PriorityThenCount,
+        size_measurement: SizeMeasurement::Raw,
+        boundary: None,
+      },
+      AlgoTestStrategy {
+        name: "savings".to_string(),
+        tiebreaker: CompareEntriesTiebreaker::PriorityThenSavings,
+        size_measurement: SizeMeasurement::Raw,
+        boundary: None,
+      },
+    ];
+
+    let reports = run_algotest(&strategies, &candidate_group_sets, &module_sizes, &FxHashMap::default());
+
+    // Equal priority and chunk count means `PriorityThenCount` falls through
+    // to cache-group-index/module-identifier ordering, while
+    // `PriorityThenSavings` picks the bigger group first — so the two
+    // strategies' reports diverge even though every other input is identical.
+    assert_eq!(reports[0].analysis.realized_groups[0].name, "low-savings");
+    assert_eq!(reports[1].analysis.realized_groups[0].name, "high-savings");
+    assert_ne!(reports[0], reports[1]);
+  }
+}
@@ -0,0 +1,138 @@
+use std::hash::{Hash, Hasher};
+
+use rspack_identifier::Identifier;
+use rustc_hash::FxHasher;
+
+/// Number of entries in the `Gear` lookup table used by the rolling hash.
+const GEAR_TABLE_SIZE: usize = 256;
+
+/// A fixed, well-distributed table of `u64`s used to fold a module's identity
+/// into the rolling fingerprint below. The values don't need to be
+/// cryptographically random, only spread across bits — the goal is stable,
+/// locally-scoped cut points, not content addressing.
+static GEAR: [u64; GEAR_TABLE_SIZE] = build_gear_table();
+
+const fn build_gear_table() -> [u64; GEAR_TABLE_SIZE] {
+  // A const-fn splitmix64, so the table can be generated at compile time
+  // without pulling in a `rand` dependency just for a fixed lookup table.
+  let mut table = [0u64; GEAR_TABLE_SIZE];
+  let mut seed: u64 = 0x9e3779b97f4a7c15;
+  let mut i = 0;
+  while i < GEAR_TABLE_SIZE {
+    seed = seed.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = seed;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^= z >> 31;
+    table[i] = z;
+    i += 1;
+  }
+  table
+}
+
+/// Size thresholds the content-defined boundary splitter is cutting to.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ContentDefinedBoundaryOptions {
+  /// Never cut before a sub-chunk reaches this size.
+  pub min_size: f64,
+  /// Below this running size, cuts use the stricter `mask_s`; at or above it
+  /// they switch to the more permissive `mask_l` so probability mass
+  /// concentrates near `normal_size`.
+  pub normal_size: f64,
+  /// Always cut once a sub-chunk reaches this size, regardless of the mask.
+  pub max_size: f64,
+}
+
+/// `mask_s`: more set bits, so `fp & mask_s == 0` is unlikely. Used while a
+/// sub-chunk is still below `normal_size`, to discourage cutting too early.
+const MASK_S: u64 = 0x0000_d93b_1f34_8000;
+/// `mask_l`: fewer set bits than `mask_s`, so it matches more often. Used once
+/// a sub-chunk has reached `normal_size`, to encourage cutting near it.
+const MASK_L: u64 = 0x0000_1903_0034_8000;
+
+/// Split an ordered sequence of `(module identifier, size)` pairs into
+/// content-defined sub-chunks using a FastCDC-style gear hash.
+///
+/// `modules` must already be sorted deterministically — see
+/// `compare_entries`'s step 5, which sorts by identifier for the same reason.
+pub(crate) fn split_by_content_defined_boundary(
+  modules: &[(Identifier, f64)],
+  options: &ContentDefinedBoundaryOptions,
+) -> Vec<Vec<Identifier>> {
+  let mut groups = Vec::new();
+  let mut current = Vec::new();
+  let mut current_size = 0.0;
+  let mut fp: u64 = 0;
+
+  for (identifier, size) in modules {
+    current.push(*identifier);
+    current_size += *size;
+    fp = (fp << 1).wrapping_add(GEAR[gear_index(identifier)]);
+
+    if current_size < options.min_size {
+      continue;
+    }
+
+    let mask = if current_size < options.normal_size {
+      MASK_S
+    } else {
+      MASK_L
+    };
+
+    if fp & mask == 0 || current_size >= options.max_size {
+      groups.push(std::mem::take(&mut current));
+      current_size = 0.0;
+      fp = 0;
+    }
+  }
+
+  if !current.is_empty() {
+    groups.push(current);
+  }
+
+  groups
+}
+
+/// Fold a module identifier down to a single byte to index into [`GEAR`].
+fn gear_index(identifier: &Identifier) -> usize {
+  let mut hasher = FxHasher::default();
+  identifier.hash(&mut hasher);
+  (hasher.finish() & 0xff) as usize
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn options() -> ContentDefinedBoundaryOptions {
+    ContentDefinedBoundaryOptions {
+      min_size: 0.0,
+      normal_size: 500.0,
+      max_size: 2_000.0,
+    }
+  }
+
+  fn modules(names: &[String]) -> Vec<(Identifier, f64)> {
+    names.iter().map(|name| (Identifier::from(name.as_str()), 100.0)).collect()
+  }
+
+  #[test]
+  fn inserting_a_module_only_perturbs_a_couple_of_chunks() {
+    let base: Vec<String> = (0..40).map(|i| format!("module-{i}")).collect();
+    let before = split_by_content_defined_boundary(&modules(&base), &options());
+
+    let mut with_insert = base.clone();
+    with_insert.insert(10, "module-new".to_string());
+    let after = split_by_content_defined_boundary(&modules(&with_insert), &options());
+
+    let unaffected = before.iter().filter(|chunk| after.contains(chunk)).count();
+    // Only the chunk the new module lands in (and, in the worst case, its
+    // neighbor) should change — everything else survives verbatim.
+    assert!(
+      before.len() - unaffected <= 2,
+      "expected at most 2 chunks to change, got {} of {} changed",
+      before.len() - unaffected,
+      before.len()
+    );
+  }
+}
@@ -0,0 +1,50 @@
+use rspack_core::SourceType;
+use rustc_hash::FxHashMap;
+
+/// Per-`SourceType` byte sizes tracked for a [`crate::module_group::ModuleGroup`].
+///
+/// A group keeps two of these side by side: the modules' raw sizes (what
+/// `Module::size` reports) and an estimated *compressed* size (see
+/// [`crate::size_estimation`]), so `minSize`/`maxSize`/`minSizeReduction` can
+/// be evaluated against whichever one the user's `splitChunks` config picks.
+pub(crate) type SplitChunkSizes = FxHashMap<SourceType, f64>;
+
+pub(crate) fn total_size(sizes: &SplitChunkSizes) -> f64 {
+  sizes.values().sum()
+}
+
+/// Which of a [`ModuleGroup`](crate::module_group::ModuleGroup)'s size maps
+/// `minSize`/`maxSize`/`minSizeReduction` should be measured against.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SizeMeasurement {
+  /// Compare against `Module::size`, i.e. the size of the generated source.
+  #[default]
+  Raw,
+  /// Compare against an estimated compressed size, which better reflects
+  /// what is actually transferred over the wire than raw source size does.
+  EstimatedCompressed,
+}
+
+/// Which tiebreaker `compare_entries` falls back on once two module groups
+/// have equal `cache_group_priority` and reference the same number of
+/// chunks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CompareEntriesTiebreaker {
+  /// Skip straight to ordering by cache group index and module identifiers.
+  /// This matches webpack's historical behavior.
+  #[default]
+  PriorityThenCount,
+  /// Additionally rank by estimated size reduction — `total_size(sizes) *
+  /// (chunks.len() - 1)`, i.e. how many bytes of duplication extracting this
+  /// group would eliminate — before falling through to cache group index.
+  PriorityThenSavings,
+}
+
+/// Per-invocation knobs that `ModuleGroup`'s comparison and size-threshold
+/// helpers need, threaded in from `optimization.splitChunks` by the splitting
+/// step that owns the candidate groups.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct SplitChunksRuntimeOptions {
+  pub tiebreaker: CompareEntriesTiebreaker,
+  pub size_measurement: SizeMeasurement,
+}
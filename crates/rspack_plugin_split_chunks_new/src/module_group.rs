@@ -1,9 +1,13 @@
 use derivative::Derivative;
 use rspack_core::{ChunkUkey, Module};
-use rspack_identifier::IdentifierSet;
-use rustc_hash::FxHashSet;
+use rspack_identifier::{Identifier, IdentifierSet};
+use rustc_hash::{FxHashMap, FxHashSet};
 
-use crate::common::SplitChunkSizes;
+use crate::boundary::{split_by_content_defined_boundary, ContentDefinedBoundaryOptions};
+use crate::common::{
+  total_size, CompareEntriesTiebreaker, SizeMeasurement, SplitChunkSizes, SplitChunksRuntimeOptions,
+};
+use crate::size_estimation::estimate_compressed_size;
 
 /// `ModuleGroup` is a abstraction of middle step for splitting chunks.
 ///
@@ -12,7 +16,7 @@ use crate::common::SplitChunkSizes;
 /// `ModuleGroup` would be transform into `Chunk` in the end.
 ///
 /// The original name of `ModuleGroup` is `ChunkInfoItem` borrowed from Webpack
-#[derive(Derivative)]
+#[derive(Derivative, Clone)]
 #[derivative(Debug)]
 pub(crate) struct ModuleGroup {
   #[derivative(Debug = "ignore")]
@@ -21,6 +25,11 @@ pub(crate) struct ModuleGroup {
   pub cache_group_priority: f64,
   pub name: String,
   pub sizes: SplitChunkSizes,
+  /// Estimated compressed counterpart of `sizes`, populated alongside it so
+  /// that `minSize`/`maxSize`/`minSizeReduction` can be measured against
+  /// over-the-wire cost instead of raw source size when configured to. See
+  /// [`crate::size_estimation`].
+  pub estimated_compressed_sizes: SplitChunkSizes,
   #[derivative(Debug = "ignore")]
   pub chunks: FxHashSet<ChunkUkey>,
 }
@@ -32,8 +41,10 @@ impl ModuleGroup {
 
     if self.modules.len() != old_len {
       module.source_types().iter().for_each(|ty| {
-        let size = self.sizes.entry(*ty).or_default();
-        *size += module.size(ty);
+        let raw_size = module.size(ty);
+        *self.sizes.entry(*ty).or_default() += raw_size;
+        *self.estimated_compressed_sizes.entry(*ty).or_default() +=
+          estimate_compressed_size(*ty, raw_size);
       });
     }
   }
@@ -44,15 +55,91 @@ impl ModuleGroup {
 
     if self.modules.len() != old_len {
       module.source_types().iter().for_each(|ty| {
+        let raw_size = module.size(ty);
+
         let size = self.sizes.entry(*ty).or_default();
-        *size -= module.size(ty);
-        *size = size.max(0.0)
+        *size -= raw_size;
+        *size = size.max(0.0);
+
+        let compressed_size = self.estimated_compressed_sizes.entry(*ty).or_default();
+        *compressed_size -= estimate_compressed_size(*ty, raw_size);
+        *compressed_size = compressed_size.max(0.0);
       });
     }
   }
+
+  /// Returns the size map that `minSize`/`maxSize`/`minSizeReduction` should
+  /// be measured against, per the `optimization.splitChunks` size
+  /// measurement config.
+  pub(crate) fn sizes_for(&self, measurement: SizeMeasurement) -> &SplitChunkSizes {
+    match measurement {
+      SizeMeasurement::Raw => &self.sizes,
+      SizeMeasurement::EstimatedCompressed => &self.estimated_compressed_sizes,
+    }
+  }
+
+  /// Returns the group's module identifiers sorted the same way
+  /// `compare_entries`'s final tiebreaker orders them. The maxSize-splitting
+  /// step that turns an over-large group into several chunks feeds this same
+  /// order into [`crate::boundary::split_by_content_defined_boundary`], so
+  /// that reordering never shifts where a cut falls — only adding or removing
+  /// a module does.
+  pub(crate) fn ordered_modules(&self) -> Vec<Identifier> {
+    let mut modules = self.modules.iter().copied().collect::<Vec<_>>();
+    modules.sort_unstable();
+    modules
+  }
+
+  /// Splits this group into several sub-groups once its combined size
+  /// exceeds `optimization.splitChunks.maxSize`, cutting at content-defined
+  /// boundaries (see [`crate::boundary`]) instead of at fixed positions, so
+  /// that a localized module change only reshuffles the sub-chunk it falls
+  /// in and, at worst, its neighbor. `module_sizes` must carry a per-`SourceType` entry for every module
+  /// in this group, as populated by `add_module`.
+  pub(crate) fn split_by_max_size(
+    &self,
+    module_sizes: &FxHashMap<Identifier, SplitChunkSizes>,
+    options: &ContentDefinedBoundaryOptions,
+  ) -> Vec<ModuleGroup> {
+    let ordered = self.ordered_modules();
+    let sized = ordered
+      .iter()
+      .map(|id| (*id, module_sizes.get(id).map(total_size).unwrap_or(0.0)))
+      .collect::<Vec<_>>();
+
+    split_by_content_defined_boundary(&sized, options)
+      .into_iter()
+      .map(|identifiers| {
+        let mut sizes = SplitChunkSizes::default();
+        let mut estimated_compressed_sizes = SplitChunkSizes::default();
+        for id in &identifiers {
+          if let Some(per_type) = module_sizes.get(id) {
+            for (ty, size) in per_type {
+              *sizes.entry(*ty).or_default() += size;
+              *estimated_compressed_sizes.entry(*ty).or_default() += estimate_compressed_size(*ty, *size);
+            }
+          }
+        }
+
+        ModuleGroup {
+          modules: identifiers.into_iter().collect(),
+          cache_group_index: self.cache_group_index,
+          cache_group_priority: self.cache_group_priority,
+          name: self.name.clone(),
+          sizes,
+          estimated_compressed_sizes,
+          chunks: self.chunks.clone(),
+        }
+      })
+      .collect()
+  }
 }
 
-pub(crate) fn compare_entries(a: &ModuleGroup, b: &ModuleGroup) -> f64 {
+pub(crate) fn compare_entries(
+  a: &ModuleGroup,
+  b: &ModuleGroup,
+  options: &SplitChunksRuntimeOptions,
+) -> f64 {
   // 1. by priority
   let diff_priority = a.cache_group_priority - b.cache_group_priority;
   if diff_priority != 0f64 {
@@ -64,13 +151,22 @@ pub(crate) fn compare_entries(a: &ModuleGroup, b: &ModuleGroup) -> f64 {
     return diff_count;
   }
 
-  // // 3. by size reduction
-  // let a_size_reduce = total_size(&a.sizes) * (a.chunks.len() - 1) as f64;
-  // let b_size_reduce = total_size(&b.sizes) * (b.chunks.len() - 1) as f64;
-  // let diff_size_reduce = a_size_reduce - b_size_reduce;
-  // if diff_size_reduce != 0f64 {
-  //   return diff_size_reduce;
-  // }
+  // 3. by size reduction
+  if options.tiebreaker == CompareEntriesTiebreaker::PriorityThenSavings {
+    // `chunks.len()` can be `0` for a rejected candidate, so guard against
+    // underflow here the same way `report.rs`'s `ModuleGroupReport` does.
+    // Measured per `options.size_measurement`, same as `satisfies_size_limits`,
+    // so this ranking honors raw vs. estimated-compressed the same way the
+    // size limits do.
+    let a_size_reduce =
+      total_size(a.sizes_for(options.size_measurement)) * a.chunks.len().saturating_sub(1) as f64;
+    let b_size_reduce =
+      total_size(b.sizes_for(options.size_measurement)) * b.chunks.len().saturating_sub(1) as f64;
+    let diff_size_reduce = a_size_reduce - b_size_reduce;
+    if diff_size_reduce != 0f64 {
+      return diff_size_reduce;
+    }
+  }
   // 4. by cache group index
   let index_diff = b.cache_group_index as f64 - a.cache_group_index as f64;
   if index_diff != 0f64 {
@@ -91,3 +187,135 @@ pub(crate) fn compare_entries(a: &ModuleGroup, b: &ModuleGroup) -> f64 {
   modules_b.sort_unstable();
   modules_a.cmp(&modules_b) as usize as f64
 }
+
+/// Picks the best candidate among cache groups competing for the same
+/// modules, i.e. `compare_entries` applied pairwise under `options` and
+/// reduced to a single winner.
+pub(crate) fn pick_best_group<'a>(
+  candidates: &'a [ModuleGroup],
+  options: &SplitChunksRuntimeOptions,
+) -> Option<&'a ModuleGroup> {
+  candidates
+    .iter()
+    .reduce(|a, b| if compare_entries(a, b, options) >= 0f64 { a } else { b })
+}
+
+/// Whether `group`'s size (measured per `options.size_measurement`) falls
+/// within `[min_size, max_size]` for every `SourceType` both sides specify,
+/// mirroring how `optimization.splitChunks.minSize`/`maxSize` are enforced
+/// per cache group.
+pub(crate) fn satisfies_size_limits(
+  group: &ModuleGroup,
+  min_size: &SplitChunkSizes,
+  max_size: &SplitChunkSizes,
+  options: &SplitChunksRuntimeOptions,
+) -> bool {
+  let sizes = group.sizes_for(options.size_measurement);
+  min_size.iter().all(|(ty, min)| sizes.get(ty).copied().unwrap_or(0.0) >= *min)
+    && max_size.iter().all(|(ty, max)| sizes.get(ty).copied().unwrap_or(0.0) <= *max)
+}
+
+#[cfg(test)]
+mod tests {
+  use rspack_core::SourceType;
+
+  use super::*;
+
+  fn group(priority: f64, chunk_count: usize, size: f64) -> ModuleGroup {
+    let mut sizes = SplitChunkSizes::default();
+    sizes.insert(SourceType::JavaScript, size);
+
+    ModuleGroup {
+      modules: std::iter::once(rspack_identifier::Identifier::from("a")).collect(),
+      cache_group_index: 0,
+      cache_group_priority: priority,
+      name: "group".to_string(),
+      sizes,
+      estimated_compressed_sizes: SplitChunkSizes::default(),
+      chunks: (0..chunk_count).map(|_| ChunkUkey::new()).collect(),
+    }
+  }
+
+  fn savings_options() -> SplitChunksRuntimeOptions {
+    SplitChunksRuntimeOptions {
+      tiebreaker: CompareEntriesTiebreaker::PriorityThenSavings,
+      size_measurement: SizeMeasurement::Raw,
+    }
+  }
+
+  #[test]
+  fn size_reduction_tiebreaker_does_not_panic_with_zero_chunks() {
+    // Same priority and chunk count (0 — a rejected candidate never claimed a
+    // chunk) reaches the size-reduction branch with `chunks.len() - 1`
+    // underflowing on both sides if not guarded by `saturating_sub`.
+    let a = group(1.0, 0, 2_000.0);
+    let b = group(1.0, 0, 1_000.0);
+    assert_eq!(compare_entries(&a, &b, &savings_options()), 0.0);
+  }
+
+  #[test]
+  fn size_reduction_tiebreaker_does_not_panic_with_one_chunk() {
+    // With exactly one referencing chunk, `chunks.len() - 1 == 0` on both
+    // sides, so both size reductions are 0 and the comparison falls through
+    // to the later steps despite the different sizes.
+    let a = group(1.0, 1, 2_000.0);
+    let b = group(1.0, 1, 1_000.0);
+    assert_eq!(compare_entries(&a, &b, &savings_options()), 0.0);
+  }
+
+  #[test]
+  fn pick_best_group_uses_the_configured_tiebreaker() {
+    let low_savings = group(1.0, 2, 1_000.0);
+    let high_savings = group(1.0, 2, 5_000.0);
+    let candidates = [low_savings, high_savings];
+
+    let winner = pick_best_group(&candidates, &savings_options()).expect("candidates is non-empty");
+    assert_eq!(total_size(&winner.sizes), 5_000.0);
+  }
+
+  #[test]
+  fn size_reduction_tiebreaker_honors_the_configured_measurement() {
+    // Same raw size on both sides, but `b`'s estimated compressed size is far
+    // smaller, so under `EstimatedCompressed` `a` should win the savings
+    // comparison even though they're tied under `Raw`.
+    let mut a = group(1.0, 2, 1_000.0);
+    a.estimated_compressed_sizes.insert(SourceType::JavaScript, 900.0);
+    let mut b = group(1.0, 2, 1_000.0);
+    b.estimated_compressed_sizes.insert(SourceType::JavaScript, 100.0);
+
+    let raw_options = savings_options();
+    assert_eq!(compare_entries(&a, &b, &raw_options), 0.0);
+
+    let compressed_options = SplitChunksRuntimeOptions {
+      size_measurement: SizeMeasurement::EstimatedCompressed,
+      ..raw_options
+    };
+    assert!(compare_entries(&a, &b, &compressed_options) > 0.0);
+  }
+
+  #[test]
+  fn satisfies_size_limits_honors_the_configured_measurement() {
+    // Raw JS compresses well (see `size_estimation`'s 0.33 ratio), so a group
+    // that's over `maxSize` raw can still be under it once measured against
+    // the estimated compressed size.
+    let mut group = group(1.0, 1, 3_000.0);
+    group
+      .estimated_compressed_sizes
+      .insert(SourceType::JavaScript, 990.0);
+
+    let mut max_size = SplitChunkSizes::default();
+    max_size.insert(SourceType::JavaScript, 1_000.0);
+
+    let raw_options = SplitChunksRuntimeOptions {
+      tiebreaker: CompareEntriesTiebreaker::PriorityThenCount,
+      size_measurement: SizeMeasurement::Raw,
+    };
+    let compressed_options = SplitChunksRuntimeOptions {
+      size_measurement: SizeMeasurement::EstimatedCompressed,
+      ..raw_options
+    };
+
+    assert!(!satisfies_size_limits(&group, &SplitChunkSizes::default(), &max_size, &raw_options));
+    assert!(satisfies_size_limits(&group, &SplitChunkSizes::default(), &max_size, &compressed_options));
+  }
+}
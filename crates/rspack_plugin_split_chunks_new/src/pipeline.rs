@@ -0,0 +1,140 @@
+use rspack_identifier::{Identifier, IdentifierSet};
+use rustc_hash::FxHashMap;
+
+use crate::boundary::ContentDefinedBoundaryOptions;
+use crate::common::{SplitChunkSizes, SplitChunksRuntimeOptions};
+use crate::module_group::{pick_best_group, satisfies_size_limits, ModuleGroup};
+
+/// Outcome of [`select_and_split_groups`]: every group it realized into a
+/// chunk, plus every candidate it turned down along the way (for reporting —
+/// see [`crate::report::SplitChunksAnalysisReport`]).
+#[derive(Debug, Default)]
+pub(crate) struct SplitResult {
+  pub realized_groups: Vec<ModuleGroup>,
+  pub rejected_groups: Vec<ModuleGroup>,
+}
+
+/// Resolves one set of competing cache-group candidates per entry of
+/// `candidate_group_sets` down to a single realized `ModuleGroup`, the same
+/// way the real `splitChunks` grouping step picks a winner for each set of
+/// modules multiple cache groups want to claim:
+///
+/// 1. `pick_best_group` under `options` picks the winner; every other
+///    candidate in the set is rejected outright.
+/// 2. The winner is rejected instead if it doesn't `satisfies_size_limits`
+///    against `min_size`/`max_size`.
+/// 3. Otherwise, if `max_size_boundary` is configured, the winner is cut into
+///    several sub-groups via `ModuleGroup::split_by_max_size`; otherwise it's
+///    realized as-is.
+pub(crate) fn select_and_split_groups(
+  candidate_group_sets: Vec<Vec<ModuleGroup>>,
+  module_sizes: &FxHashMap<Identifier, SplitChunkSizes>,
+  min_size: &SplitChunkSizes,
+  max_size: &SplitChunkSizes,
+  max_size_boundary: Option<&ContentDefinedBoundaryOptions>,
+  options: &SplitChunksRuntimeOptions,
+) -> SplitResult {
+  let mut result = SplitResult::default();
+
+  for mut set in candidate_group_sets {
+    if set.is_empty() {
+      continue;
+    }
+
+    let winner_index = {
+      let winner = pick_best_group(&set, options).expect("set is non-empty");
+      set.iter().position(|candidate| std::ptr::eq(candidate, winner)).expect("winner is in set")
+    };
+    let winner = set.remove(winner_index);
+    result.rejected_groups.extend(set);
+
+    if !satisfies_size_limits(&winner, min_size, max_size, options) {
+      result.rejected_groups.push(winner);
+      continue;
+    }
+
+    match max_size_boundary {
+      Some(boundary_options) => {
+        result.realized_groups.extend(winner.split_by_max_size(module_sizes, boundary_options))
+      }
+      None => result.realized_groups.push(winner),
+    }
+  }
+
+  result
+}
+
+/// Union of every module captured by `result`'s realized groups.
+pub(crate) fn captured_modules(result: &SplitResult) -> IdentifierSet {
+  result.realized_groups.iter().flat_map(|group| group.modules.iter().copied()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use rspack_core::{ChunkUkey, SourceType};
+
+  use super::*;
+  use crate::common::{CompareEntriesTiebreaker, SizeMeasurement};
+
+  fn group(name: &str, priority: f64, size: f64, chunk_count: usize) -> ModuleGroup {
+    let mut sizes = SplitChunkSizes::default();
+    sizes.insert(SourceType::JavaScript, size);
+
+    ModuleGroup {
+      modules: std::iter::once(Identifier::from(name)).collect(),
+      cache_group_index: 0,
+      cache_group_priority: priority,
+      name: name.to_string(),
+      sizes,
+      estimated_compressed_sizes: SplitChunkSizes::default(),
+      chunks: (0..chunk_count).map(|_| ChunkUkey::new()).collect(),
+    }
+  }
+
+  fn options() -> SplitChunksRuntimeOptions {
+    SplitChunksRuntimeOptions {
+      tiebreaker: CompareEntriesTiebreaker::PriorityThenCount,
+      size_measurement: SizeMeasurement::Raw,
+    }
+  }
+
+  #[test]
+  fn realizes_the_winner_and_rejects_the_rest_of_each_set() {
+    let low_priority = group("low", 1.0, 1_000.0, 1);
+    let high_priority = group("high", 2.0, 1_000.0, 1);
+
+    let result = select_and_split_groups(
+      vec![vec![low_priority, high_priority]],
+      &FxHashMap::default(),
+      &SplitChunkSizes::default(),
+      &SplitChunkSizes::default(),
+      None,
+      &options(),
+    );
+
+    assert_eq!(result.realized_groups.len(), 1);
+    assert_eq!(result.realized_groups[0].name, "high");
+    assert_eq!(result.rejected_groups.len(), 1);
+    assert_eq!(result.rejected_groups[0].name, "low");
+  }
+
+  #[test]
+  fn rejects_a_winner_that_violates_max_size() {
+    let winner = group("winner", 1.0, 5_000.0, 1);
+
+    let mut max_size = SplitChunkSizes::default();
+    max_size.insert(SourceType::JavaScript, 1_000.0);
+
+    let result = select_and_split_groups(
+      vec![vec![winner]],
+      &FxHashMap::default(),
+      &SplitChunkSizes::default(),
+      &max_size,
+      None,
+      &options(),
+    );
+
+    assert!(result.realized_groups.is_empty());
+    assert_eq!(result.rejected_groups.len(), 1);
+  }
+}
@@ -0,0 +1,134 @@
+use rspack_core::SourceType;
+use rspack_identifier::{Identifier, IdentifierSet};
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use crate::common::total_size;
+use crate::module_group::ModuleGroup;
+
+/// JSON-serializable snapshot of one [`ModuleGroup`]'s split-chunks decision.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ModuleGroupReport {
+  pub name: String,
+  pub modules: Vec<String>,
+  pub sizes: Vec<(SourceType, f64)>,
+  pub estimated_compressed_sizes: Vec<(SourceType, f64)>,
+  pub cache_group_index: usize,
+  pub cache_group_priority: f64,
+  pub chunk_count: usize,
+  /// `total_size(sizes) * (chunk_count - 1)`: the bytes that were (if this
+  /// group was realized) or would have been (if it was rejected) saved by
+  /// extracting the shared modules into one chunk instead of duplicating
+  /// them into every referencing chunk.
+  pub reclaimable_bytes: f64,
+}
+
+fn sorted_sizes(sizes: &crate::common::SplitChunkSizes) -> Vec<(SourceType, f64)> {
+  let mut sizes = sizes.iter().map(|(ty, size)| (*ty, *size)).collect::<Vec<_>>();
+  sizes.sort_unstable_by_key(|(ty, _)| format!("{ty:?}"));
+  sizes
+}
+
+impl From<&ModuleGroup> for ModuleGroupReport {
+  fn from(group: &ModuleGroup) -> Self {
+    let chunk_count = group.chunks.len();
+
+    Self {
+      name: group.name.clone(),
+      modules: group.ordered_modules().iter().map(|id| id.to_string()).collect(),
+      sizes: sorted_sizes(&group.sizes),
+      estimated_compressed_sizes: sorted_sizes(&group.estimated_compressed_sizes),
+      cache_group_index: group.cache_group_index,
+      cache_group_priority: group.cache_group_priority,
+      chunk_count,
+      reclaimable_bytes: total_size(&group.sizes) * chunk_count.saturating_sub(1) as f64,
+    }
+  }
+}
+
+/// Modules present in more than one output chunk's module set that no
+/// realized group claimed — duplication the split pass failed to catch.
+fn find_uncaptured_duplicates<'a>(
+  chunk_modules: impl IntoIterator<Item = &'a IdentifierSet>,
+  captured_by_realized_groups: &IdentifierSet,
+) -> Vec<Identifier> {
+  let mut occurrences: FxHashMap<Identifier, usize> = FxHashMap::default();
+  for modules in chunk_modules {
+    for id in modules {
+      *occurrences.entry(*id).or_default() += 1;
+    }
+  }
+
+  let mut duplicated = occurrences
+    .into_iter()
+    .filter(|(id, count)| *count > 1 && !captured_by_realized_groups.contains(id))
+    .map(|(id, _)| id)
+    .collect::<Vec<_>>();
+  duplicated.sort_unstable();
+  duplicated
+}
+
+/// Full split-chunks analysis for one compilation: every group the grouping
+/// step considered, whether or not it was ultimately realized into a chunk,
+/// plus any modules that ended up duplicated across output chunks because no
+/// group captured them.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SplitChunksAnalysisReport {
+  pub realized_groups: Vec<ModuleGroupReport>,
+  pub rejected_groups: Vec<ModuleGroupReport>,
+  pub duplicated_modules: Vec<String>,
+}
+
+impl SplitChunksAnalysisReport {
+  /// `chunk_modules` is each output chunk's final module set (after
+  /// realized groups were extracted), used to find modules that are still
+  /// duplicated across chunks despite the split pass.
+  pub(crate) fn build<'a>(
+    realized_groups: &'a [ModuleGroup],
+    rejected_groups: impl IntoIterator<Item = &'a ModuleGroup>,
+    chunk_modules: impl IntoIterator<Item = &'a IdentifierSet>,
+  ) -> Self {
+    let captured_by_realized_groups: IdentifierSet =
+      realized_groups.iter().flat_map(|group| group.modules.iter().copied()).collect();
+    let duplicated_modules = find_uncaptured_duplicates(chunk_modules, &captured_by_realized_groups);
+
+    Self {
+      realized_groups: realized_groups.iter().map(ModuleGroupReport::from).collect(),
+      rejected_groups: rejected_groups.into_iter().map(ModuleGroupReport::from).collect(),
+      duplicated_modules: duplicated_modules.iter().map(|id| id.to_string()).collect(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn group(name: &str, modules: &[&str], chunk_count: usize) -> ModuleGroup {
+    ModuleGroup {
+      modules: modules.iter().map(|m| Identifier::from(*m)).collect(),
+      cache_group_index: 0,
+      cache_group_priority: 0.0,
+      name: name.to_string(),
+      sizes: crate::common::SplitChunkSizes::default(),
+      estimated_compressed_sizes: crate::common::SplitChunkSizes::default(),
+      chunks: (0..chunk_count).map(|_| rspack_core::ChunkUkey::new()).collect(),
+    }
+  }
+
+  #[test]
+  fn flags_modules_duplicated_across_chunks_that_no_group_captured() {
+    let realized = [group("vendors", &["a"], 2)];
+    let chunk_a: IdentifierSet = ["a", "b", "c"].iter().map(|m| Identifier::from(*m)).collect();
+    let chunk_b: IdentifierSet = ["a", "c"].iter().map(|m| Identifier::from(*m)).collect();
+
+    let report = SplitChunksAnalysisReport::build(&realized, std::iter::empty(), [&chunk_a, &chunk_b]);
+
+    // `a` is captured by the realized `vendors` group, so it's not flagged
+    // even though it appears in both chunks. `c` appears in both chunks and
+    // was captured by nothing.
+    assert_eq!(report.duplicated_modules, vec![Identifier::from("c").to_string()]);
+  }
+}
@@ -0,0 +1,22 @@
+use rspack_core::SourceType;
+
+/// Rough compression ratios observed across typical web bundles, used as a
+/// cheap stand-in for running gzip/brotli over every module on every size
+/// check. Keyed by `SourceType` because text sources (JS, CSS) compress far
+/// better than assets that are already compressed (images, fonts) or encoded
+/// as a data URL.
+fn estimated_compression_ratio(ty: SourceType) -> f64 {
+  match ty {
+    SourceType::JavaScript | SourceType::Css | SourceType::Html => 0.33,
+    SourceType::Asset => 0.9,
+    _ => 0.5,
+  }
+}
+
+/// Estimate the compressed size of `raw_size` bytes of `ty` without running
+/// an actual compressor, by applying a fixed ratio. This is intentionally
+/// coarse — good enough to rank module groups against `minSize`/`maxSize`
+/// thresholds, not to predict exact transfer sizes.
+pub(crate) fn estimate_compressed_size(ty: SourceType, raw_size: f64) -> f64 {
+  raw_size * estimated_compression_ratio(ty)
+}